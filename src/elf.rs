@@ -0,0 +1,532 @@
+use std::io::{BufReader, Read, Seek, SeekFrom, Write};
+
+use crate::error::{Error, Result};
+use crate::header::ElfHeader;
+use crate::note::{parse_notes, Note};
+use crate::program_header::{ProgramHeader, PT_LOAD};
+use crate::reloc::{ElfRel, ElfRela, Relocation, ResolvedRelocation, SHT_REL, SHT_RELA};
+use crate::section_header::SectionHeader;
+use crate::symbol::{ElfSymbol, SHT_DYNSYM, SHT_SYMTAB};
+
+/// Upper bound on the size of a `load_image`/`load_image_with_base` buffer.
+/// Real PT_LOAD address ranges (including BSS) fit comfortably under this;
+/// anything larger is almost certainly a corrupted or hostile memsz/vaddr.
+const MAX_IMAGE_SIZE: u64 = 1 << 32;
+
+#[derive(Debug)]
+pub struct Elf<'a, R: Read + Seek> {
+    pub buffer: &'a mut BufReader<R>,
+    pub elf_header: ElfHeader,
+    pub program_headers: Vec<ProgramHeader>,
+    pub section_headers: Vec<SectionHeader>,
+}
+
+impl<'a, R: Read + Seek> Elf<'a, R> {
+    pub fn load_buffer(buffer: &'a mut BufReader<R>) -> Result<Self> {
+        let elf_header = ElfHeader::read_elf_header(buffer)?;
+        let program_headers = ProgramHeader::read_program_headers(buffer, &elf_header)?;
+        let section_headers = SectionHeader::read_section_headers(buffer, &elf_header)?;
+        Ok(Self {
+            buffer,
+            elf_header,
+            program_headers,
+            section_headers,
+        })
+    }
+
+    pub fn write<W: Write + Seek>(&mut self, writer: &mut W) -> Result<()> {
+        let endianness = self.elf_header.endianness();
+
+        // Copy the whole source file across first so sections/segments the
+        // crate doesn't model (section bodies, string tables, padding, ...)
+        // survive the round trip; the header/table writes below then patch
+        // their slice of that copy in place.
+        let current_pos = self.buffer.stream_position()?;
+        let file_len = self.buffer.seek(SeekFrom::End(0))?;
+        self.buffer.seek(SeekFrom::Start(0))?;
+        let mut contents = vec![0u8; file_len as usize];
+        self.buffer.read_exact(&mut contents)?;
+        self.buffer.seek(SeekFrom::Start(current_pos))?;
+
+        writer.seek(SeekFrom::Start(0))?;
+        writer.write_all(&contents)?;
+
+        writer.seek(SeekFrom::Start(0))?;
+        self.elf_header.write(writer)?;
+
+        writer.seek(SeekFrom::Start(self.elf_header.phoff()))?;
+        for program_header in &self.program_headers {
+            program_header.write(writer, endianness)?;
+        }
+
+        writer.seek(SeekFrom::Start(self.elf_header.shoff()))?;
+        for section_header in &self.section_headers {
+            section_header.write(writer, endianness)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn read_program_bytes(&mut self, idx: usize) -> Result<Vec<u8>> {
+        let program_header = self.program_headers.get(idx).unwrap();
+        let mut out_buffer: Vec<u8> = Vec::with_capacity(program_header.filesz() as usize);
+
+        let offset = program_header.offset();
+
+        self.buffer.seek(SeekFrom::Start(offset))?;
+        let mut handle = self.buffer.take(program_header.filesz());
+        handle.read_to_end(&mut out_buffer)?;
+        Ok(out_buffer)
+    }
+
+    pub fn section_name(&mut self, idx: usize) -> Result<String> {
+        self.string_at(self.elf_header.shstrndx() as usize, idx)
+    }
+
+    pub fn section_by_name(&mut self, name: &str) -> Result<Option<&SectionHeader>> {
+        let idx = self.section_index_by_name(name)?;
+        Ok(idx.map(|i| &self.section_headers[i]))
+    }
+
+    fn section_index_by_name(&mut self, name: &str) -> Result<Option<usize>> {
+        for i in 0..self.section_headers.len() {
+            let name_offset = self.section_headers[i].name() as usize;
+            if self.section_name(name_offset)? == name {
+                return Ok(Some(i));
+            }
+        }
+        Ok(None)
+    }
+
+    pub fn symbols(&mut self, section_idx: usize) -> Result<Vec<(String, ElfSymbol)>> {
+        let section = self
+            .section_headers
+            .get(section_idx)
+            .ok_or(Error::OutOfBounds {
+                offset: section_idx as u64,
+                len: 0,
+            })?;
+
+        if section.typ() != SHT_SYMTAB && section.typ() != SHT_DYNSYM {
+            return Err(Error::Malformed("section is not a symbol table"));
+        }
+
+        let strtab_idx = section.link() as usize;
+        let offset = section.offset();
+        let class = self.elf_header.class();
+        let endianness = self.elf_header.endianness();
+        let entsize = ElfSymbol::entsize(class);
+        let count = section.size() as usize / entsize;
+
+        // Read every raw entry in one sequential pass first: string_at() below
+        // seeks the shared buffer around to resolve names, so interleaving it
+        // with the symtab read would leave the cursor in the wrong place for
+        // the next entry.
+        self.buffer.seek(SeekFrom::Start(offset))?;
+        let mut raw_symbols = Vec::with_capacity(count);
+        for _ in 0..count {
+            raw_symbols.push(ElfSymbol::read(self.buffer, class, endianness)?);
+        }
+
+        let mut symbols = Vec::with_capacity(count);
+        for symbol in raw_symbols {
+            let name = self.string_at(strtab_idx, symbol.name() as usize)?;
+            symbols.push((name, symbol));
+        }
+
+        Ok(symbols)
+    }
+
+    pub fn relocations(&mut self, section_idx: usize) -> Result<Vec<ResolvedRelocation>> {
+        let section = self
+            .section_headers
+            .get(section_idx)
+            .ok_or(Error::OutOfBounds {
+                offset: section_idx as u64,
+                len: 0,
+            })?;
+
+        let is_rela = match section.typ() {
+            t if t == SHT_RELA => true,
+            t if t == SHT_REL => false,
+            _ => return Err(Error::Malformed("section is not a relocation table")),
+        };
+        let link_idx = section.link() as usize;
+        let offset = section.offset();
+        let size = section.size();
+
+        let class = self.elf_header.class();
+        let endianness = self.elf_header.endianness();
+        let entsize = if is_rela {
+            ElfRela::entsize(class)
+        } else {
+            ElfRel::entsize(class)
+        };
+        let count = size as usize / entsize;
+
+        let symtab = self.symbols(link_idx)?;
+
+        self.buffer.seek(SeekFrom::Start(offset))?;
+        let mut relocations = Vec::with_capacity(count);
+        for _ in 0..count {
+            let reloc = if is_rela {
+                Relocation::Rela(ElfRela::read(self.buffer, class, endianness)?)
+            } else {
+                Relocation::Rel(ElfRel::read(self.buffer, class, endianness)?)
+            };
+            let symbol = symtab.get(reloc.sym() as usize).cloned();
+            relocations.push((reloc, symbol));
+        }
+
+        Ok(relocations)
+    }
+
+    pub fn load_image(&mut self) -> Result<Vec<u8>> {
+        Ok(self.load_image_with_base()?.0)
+    }
+
+    pub fn load_image_with_base(&mut self) -> Result<(Vec<u8>, u64)> {
+        let segments: Vec<(u64, u64, u64, u64)> = self
+            .program_headers
+            .iter()
+            .filter(|ph| ph.typ() == PT_LOAD)
+            .map(|ph| (ph.vaddr(), ph.memsz(), ph.filesz(), ph.offset()))
+            .collect();
+
+        let base = segments
+            .iter()
+            .map(|(vaddr, ..)| *vaddr)
+            .min()
+            .ok_or(Error::Malformed("no PT_LOAD segments"))?;
+        let end = segments
+            .iter()
+            .map(|(vaddr, memsz, ..)| vaddr + memsz)
+            .max()
+            .unwrap();
+
+        let image_len = end - base;
+        if image_len > MAX_IMAGE_SIZE {
+            return Err(Error::Malformed(
+                "PT_LOAD address range is larger than any plausible in-memory image",
+            ));
+        }
+
+        let mut image = vec![0u8; image_len as usize];
+        for (vaddr, memsz, filesz, offset) in segments {
+            if filesz > memsz {
+                return Err(Error::Malformed("segment filesz exceeds memsz"));
+            }
+            let start = (vaddr - base) as usize;
+            self.buffer.seek(SeekFrom::Start(offset))?;
+            self.buffer
+                .read_exact(&mut image[start..start + filesz as usize])?;
+        }
+
+        Ok((image, base))
+    }
+
+    pub fn notes(&mut self, section_idx: usize) -> Result<Vec<Note>> {
+        let section = self
+            .section_headers
+            .get(section_idx)
+            .ok_or(Error::OutOfBounds {
+                offset: section_idx as u64,
+                len: 0,
+            })?;
+        let offset = section.offset();
+        let size = section.size();
+
+        let data = self.read_bounded(offset, size)?;
+        parse_notes(&data, self.elf_header.endianness())
+    }
+
+    pub fn build_id(&mut self) -> Result<Option<String>> {
+        let idx = match self.section_index_by_name(".note.gnu.build-id")? {
+            Some(idx) => idx,
+            None => return Ok(None),
+        };
+
+        let notes = self.notes(idx)?;
+        let build_id = notes
+            .into_iter()
+            .find(|n| n.name == b"GNU\0" && n.typ == 3)
+            .map(|n| n.descriptor.iter().map(|b| format!("{b:02x}")).collect());
+
+        Ok(build_id)
+    }
+
+    fn string_at(&mut self, strtab_idx: usize, offset: usize) -> Result<String> {
+        let strtab = self
+            .section_headers
+            .get(strtab_idx)
+            .ok_or(Error::Malformed("missing string table section"))?;
+        let strtab_offset = strtab.offset();
+        let strtab_size = strtab.size();
+
+        let strtab_data = self.read_bounded(strtab_offset, strtab_size)?;
+
+        let name_bytes = strtab_data.get(offset..).ok_or(Error::OutOfBounds {
+            offset: offset as u64,
+            len: 0,
+        })?;
+        let end = name_bytes.iter().position(|&b| b == 0).unwrap_or(name_bytes.len());
+        Ok(String::from_utf8_lossy(&name_bytes[..end]).into_owned())
+    }
+
+    fn file_len(&mut self) -> Result<u64> {
+        let current_pos = self.buffer.stream_position()?;
+        let file_len = self.buffer.seek(SeekFrom::End(0))?;
+        self.buffer.seek(SeekFrom::Start(current_pos))?;
+        Ok(file_len)
+    }
+
+    fn read_bounded(&mut self, offset: u64, size: u64) -> Result<Vec<u8>> {
+        let file_len = self.file_len()?;
+
+        if offset.checked_add(size).is_none_or(|end| end > file_len) {
+            return Err(Error::OutOfBounds {
+                offset,
+                len: size as usize,
+            });
+        }
+
+        let mut data = vec![0u8; size as usize];
+        self.buffer.seek(SeekFrom::Start(offset))?;
+        self.buffer.read_exact(&mut data)?;
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use crate::ident::{Endianness, ELFCLASS64};
+    use crate::symbol::SHT_SYMTAB;
+    use crate::writer::{write_u16, write_u32, write_u64};
+
+    // Builds a minimal in-memory ELF64 LE image with one string table, one
+    // symbol table (null, "foo", "bar"), and one .rela section with a single
+    // entry pointing at "foo", so symbols()/relocations() can be exercised
+    // end to end without a file on disk.
+    fn build_elf() -> Vec<u8> {
+        let endianness = Endianness::Little;
+        let mut buf = vec![];
+
+        // e_ident
+        let mut ident = [0u8; 16];
+        ident[0..4].copy_from_slice(b"\x7fELF");
+        ident[4] = ELFCLASS64;
+        ident[5] = 1; // ELFDATA2LSB
+        ident[6] = 1; // EI_VERSION
+        buf.extend_from_slice(&ident);
+
+        write_u16(&mut buf, 1, endianness).unwrap(); // e_type
+        write_u16(&mut buf, 0x3e, endianness).unwrap(); // e_machine
+        write_u32(&mut buf, 1, endianness).unwrap(); // e_version
+        write_u64(&mut buf, 0, endianness).unwrap(); // e_entry
+        write_u64(&mut buf, 0, endianness).unwrap(); // e_phoff
+        write_u64(&mut buf, 169, endianness).unwrap(); // e_shoff
+        write_u32(&mut buf, 0, endianness).unwrap(); // e_flags
+        write_u16(&mut buf, 64, endianness).unwrap(); // e_ehsize
+        write_u16(&mut buf, 56, endianness).unwrap(); // e_phentsize
+        write_u16(&mut buf, 0, endianness).unwrap(); // e_phnum
+        write_u16(&mut buf, 64, endianness).unwrap(); // e_shentsize
+        write_u16(&mut buf, 4, endianness).unwrap(); // e_shnum
+        write_u16(&mut buf, 1, endianness).unwrap(); // e_shstrndx
+        assert_eq!(buf.len(), 64);
+
+        // .strtab at offset 64: "\0foo\0bar\0"
+        let strtab_offset = buf.len() as u64;
+        buf.extend_from_slice(b"\0foo\0bar\0");
+        let strtab_size = buf.len() as u64 - strtab_offset;
+
+        // .symtab at offset 73: null, "foo", "bar"
+        let symtab_offset = buf.len() as u64;
+        // null symbol
+        write_u32(&mut buf, 0, endianness).unwrap();
+        buf.push(0); // info
+        buf.push(0); // other
+        write_u16(&mut buf, 0, endianness).unwrap(); // shndx
+        write_u64(&mut buf, 0, endianness).unwrap(); // value
+        write_u64(&mut buf, 0, endianness).unwrap(); // size
+        // "foo"
+        write_u32(&mut buf, 1, endianness).unwrap();
+        buf.push(0);
+        buf.push(0);
+        write_u16(&mut buf, 0, endianness).unwrap();
+        write_u64(&mut buf, 0x1000, endianness).unwrap();
+        write_u64(&mut buf, 4, endianness).unwrap();
+        // "bar"
+        write_u32(&mut buf, 5, endianness).unwrap();
+        buf.push(0);
+        buf.push(0);
+        write_u16(&mut buf, 0, endianness).unwrap();
+        write_u64(&mut buf, 0x2000, endianness).unwrap();
+        write_u64(&mut buf, 8, endianness).unwrap();
+        let symtab_size = buf.len() as u64 - symtab_offset;
+
+        // .rela at offset 145: one entry referencing symbol 1 ("foo")
+        let rela_offset = buf.len() as u64;
+        write_u64(&mut buf, 0x3000, endianness).unwrap(); // r_offset
+        write_u64(&mut buf, (1u64 << 32) | 1, endianness).unwrap(); // r_info: sym=1, type=1
+        write_u64(&mut buf, 0x10, endianness).unwrap(); // r_addend
+        let rela_size = buf.len() as u64 - rela_offset;
+
+        assert_eq!(buf.len(), 169);
+
+        // section headers
+        let write_shdr = |buf: &mut Vec<u8>,
+                           name: u32,
+                           typ: u32,
+                           offset: u64,
+                           size: u64,
+                           link: u32,
+                           entsize: u64| {
+            write_u32(buf, name, endianness).unwrap(); // sh_name
+            write_u32(buf, typ, endianness).unwrap();
+            write_u64(buf, 0, endianness).unwrap(); // sh_flags
+            write_u64(buf, 0, endianness).unwrap(); // sh_addr
+            write_u64(buf, offset, endianness).unwrap();
+            write_u64(buf, size, endianness).unwrap();
+            write_u32(buf, link, endianness).unwrap();
+            write_u32(buf, 0, endianness).unwrap(); // sh_info
+            write_u64(buf, 8, endianness).unwrap(); // sh_addralign
+            write_u64(buf, entsize, endianness).unwrap();
+        };
+        write_shdr(&mut buf, 0, 0, 0, 0, 0, 0); // null section
+        write_shdr(&mut buf, 0, 3, strtab_offset, strtab_size, 0, 0); // SHT_STRTAB
+        // Reuse "foo"/"bar" from the string table as the section names too,
+        // so section_name()/section_by_name() have something to resolve.
+        write_shdr(&mut buf, 1, SHT_SYMTAB, symtab_offset, symtab_size, 1, 24);
+        write_shdr(&mut buf, 5, SHT_RELA, rela_offset, rela_size, 2, 24);
+
+        buf
+    }
+
+    #[test]
+    fn symbols_resolves_names_across_multiple_entries() {
+        let data = build_elf();
+        let mut reader = BufReader::new(Cursor::new(data));
+        let mut elf = Elf::load_buffer(&mut reader).unwrap();
+
+        let symbols = elf.symbols(2).unwrap();
+        let names: Vec<&str> = symbols.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["", "foo", "bar"]);
+        assert_eq!(symbols[1].1.value(), 0x1000);
+        assert_eq!(symbols[2].1.size(), 8);
+    }
+
+    #[test]
+    fn relocations_resolve_their_symbol() {
+        let data = build_elf();
+        let mut reader = BufReader::new(Cursor::new(data));
+        let mut elf = Elf::load_buffer(&mut reader).unwrap();
+
+        let relocations = elf.relocations(3).unwrap();
+        assert_eq!(relocations.len(), 1);
+        let (reloc, symbol) = &relocations[0];
+        assert_eq!(reloc.offset(), 0x3000);
+        assert_eq!(reloc.addend(), Some(0x10));
+        let (name, sym) = symbol.as_ref().unwrap();
+        assert_eq!(name, "foo");
+        assert_eq!(sym.value(), 0x1000);
+    }
+
+    #[test]
+    fn section_name_and_section_by_name_resolve_sections() {
+        let data = build_elf();
+        let mut reader = BufReader::new(Cursor::new(data));
+        let mut elf = Elf::load_buffer(&mut reader).unwrap();
+
+        // section_name() takes a raw name-table offset (as stored in sh_name),
+        // not a section index.
+        assert_eq!(elf.section_name(1).unwrap(), "foo");
+        assert_eq!(elf.section_name(5).unwrap(), "bar");
+
+        let symtab = elf.section_by_name("foo").unwrap().unwrap();
+        assert_eq!(symtab.typ(), SHT_SYMTAB);
+
+        assert!(elf.section_by_name("nonexistent").unwrap().is_none());
+    }
+
+    // Minimal ELF64 LE image with a single PT_LOAD header and no sections,
+    // for exercising load_image()/load_image_with_base() in isolation.
+    fn build_elf_with_load_segment(vaddr: u64, memsz: u64, filesz: u64) -> Vec<u8> {
+        let endianness = Endianness::Little;
+        let mut buf = vec![];
+
+        let mut ident = [0u8; 16];
+        ident[0..4].copy_from_slice(b"\x7fELF");
+        ident[4] = ELFCLASS64;
+        ident[5] = 1; // ELFDATA2LSB
+        ident[6] = 1; // EI_VERSION
+        buf.extend_from_slice(&ident);
+
+        write_u16(&mut buf, 1, endianness).unwrap(); // e_type
+        write_u16(&mut buf, 0x3e, endianness).unwrap(); // e_machine
+        write_u32(&mut buf, 1, endianness).unwrap(); // e_version
+        write_u64(&mut buf, 0, endianness).unwrap(); // e_entry
+        write_u64(&mut buf, 64, endianness).unwrap(); // e_phoff
+        write_u64(&mut buf, 0, endianness).unwrap(); // e_shoff
+        write_u32(&mut buf, 0, endianness).unwrap(); // e_flags
+        write_u16(&mut buf, 64, endianness).unwrap(); // e_ehsize
+        write_u16(&mut buf, 56, endianness).unwrap(); // e_phentsize
+        write_u16(&mut buf, 1, endianness).unwrap(); // e_phnum
+        write_u16(&mut buf, 64, endianness).unwrap(); // e_shentsize
+        write_u16(&mut buf, 0, endianness).unwrap(); // e_shnum
+        write_u16(&mut buf, 0, endianness).unwrap(); // e_shstrndx
+        assert_eq!(buf.len(), 64);
+
+        write_u32(&mut buf, PT_LOAD, endianness).unwrap(); // p_type
+        write_u32(&mut buf, 0, endianness).unwrap(); // p_flags
+        write_u64(&mut buf, 0, endianness).unwrap(); // p_offset
+        write_u64(&mut buf, vaddr, endianness).unwrap(); // p_vaddr
+        write_u64(&mut buf, vaddr, endianness).unwrap(); // p_paddr
+        write_u64(&mut buf, filesz, endianness).unwrap(); // p_filesz
+        write_u64(&mut buf, memsz, endianness).unwrap(); // p_memsz
+        write_u64(&mut buf, 1, endianness).unwrap(); // p_align
+
+        buf
+    }
+
+    #[test]
+    fn load_image_rejects_implausibly_large_memsz_instead_of_aborting() {
+        let data = build_elf_with_load_segment(0, 1 << 60, 0);
+        let mut reader = BufReader::new(Cursor::new(data));
+        let mut elf = Elf::load_buffer(&mut reader).unwrap();
+
+        assert!(matches!(elf.load_image(), Err(Error::Malformed(_))));
+    }
+
+    #[test]
+    fn load_image_maps_a_well_formed_load_segment() {
+        let data = build_elf_with_load_segment(0x1000, 8, 0);
+        let mut reader = BufReader::new(Cursor::new(data));
+        let mut elf = Elf::load_buffer(&mut reader).unwrap();
+
+        let image = elf.load_image().unwrap();
+        assert_eq!(image.len(), 8);
+    }
+
+    #[test]
+    fn write_round_trips_a_file_that_re_parses_identically() {
+        let data = build_elf();
+        let mut reader = BufReader::new(Cursor::new(data.clone()));
+        let mut elf = Elf::load_buffer(&mut reader).unwrap();
+
+        let mut out = Cursor::new(vec![]);
+        elf.write(&mut out).unwrap();
+        let written = out.into_inner();
+
+        assert_eq!(written, data);
+
+        let mut reparsed_reader = BufReader::new(Cursor::new(written));
+        let mut reparsed = Elf::load_buffer(&mut reparsed_reader).unwrap();
+        assert_eq!(reparsed.elf_header.shnum(), elf.elf_header.shnum());
+        assert_eq!(
+            reparsed.symbols(2).unwrap().len(),
+            elf.symbols(2).unwrap().len()
+        );
+    }
+}
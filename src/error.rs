@@ -0,0 +1,42 @@
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    InvalidClass(u8),
+    InvalidData(u8),
+    InvalidEntrySize { expected: usize, found: u16 },
+    UnknownSection(String),
+    OutOfBounds { offset: u64, len: usize },
+    Malformed(&'static str),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "io error: {e}"),
+            Error::InvalidClass(b) => write!(f, "invalid EI_CLASS byte: {b:#x}"),
+            Error::InvalidData(b) => write!(f, "invalid EI_DATA byte: {b:#x}"),
+            Error::InvalidEntrySize { expected, found } => write!(
+                f,
+                "unexpected entry size: expected {expected}, found {found}"
+            ),
+            Error::UnknownSection(name) => write!(f, "no section named {name:?}"),
+            Error::OutOfBounds { offset, len } => {
+                write!(f, "read of {len} bytes at offset {offset:#x} is out of bounds")
+            }
+            Error::Malformed(msg) => write!(f, "malformed ELF: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,282 @@
+use std::io::{BufReader, Read, Seek, Write};
+
+use crate::error::Result;
+use crate::ident::{ElfClass, Endianness, EI_CLASS, EI_DATA};
+use crate::reader::{read_u16, read_u32, read_u64};
+use crate::writer::{write_u16, write_u32, write_u64, ToWriter};
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ElfHeader32 {
+    pub ident: [u8; 16],
+    pub typ: u16,
+    pub machine: u16,
+    pub version: u32,
+    pub entry: u32,
+    pub phoff: u32,
+    pub shoff: u32,
+    pub flags: u32,
+    pub ehsize: u16,
+    pub phentsize: u16,
+    pub phnum: u16,
+    pub shentsize: u16,
+    pub shnum: u16,
+    pub shstrndx: u16,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ElfHeader64 {
+    pub ident: [u8; 16],
+    pub typ: u16,
+    pub machine: u16,
+    pub version: u32,
+    pub entry: u64,
+    pub phoff: u64,
+    pub shoff: u64,
+    pub flags: u32,
+    pub ehsize: u16,
+    pub phentsize: u16,
+    pub phnum: u16,
+    pub shentsize: u16,
+    pub shnum: u16,
+    pub shstrndx: u16,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ElfHeader {
+    Elf32(ElfHeader32),
+    Elf64(ElfHeader64),
+}
+
+impl ElfHeader {
+    pub fn read_elf_header<R: Read + Seek>(buffer: &mut BufReader<R>) -> Result<ElfHeader> {
+        let mut ident = [0u8; 16];
+        buffer.read_exact(&mut ident)?;
+        let class = ElfClass::from_ident_byte(ident[EI_CLASS])?;
+        let endianness = Endianness::from_ident_byte(ident[EI_DATA])?;
+
+        match class {
+            ElfClass::Elf32 => Ok(ElfHeader::Elf32(ElfHeader32 {
+                ident,
+                typ: read_u16(buffer, endianness)?,
+                machine: read_u16(buffer, endianness)?,
+                version: read_u32(buffer, endianness)?,
+                entry: read_u32(buffer, endianness)?,
+                phoff: read_u32(buffer, endianness)?,
+                shoff: read_u32(buffer, endianness)?,
+                flags: read_u32(buffer, endianness)?,
+                ehsize: read_u16(buffer, endianness)?,
+                phentsize: read_u16(buffer, endianness)?,
+                phnum: read_u16(buffer, endianness)?,
+                shentsize: read_u16(buffer, endianness)?,
+                shnum: read_u16(buffer, endianness)?,
+                shstrndx: read_u16(buffer, endianness)?,
+            })),
+            ElfClass::Elf64 => Ok(ElfHeader::Elf64(ElfHeader64 {
+                ident,
+                typ: read_u16(buffer, endianness)?,
+                machine: read_u16(buffer, endianness)?,
+                version: read_u32(buffer, endianness)?,
+                entry: read_u64(buffer, endianness)?,
+                phoff: read_u64(buffer, endianness)?,
+                shoff: read_u64(buffer, endianness)?,
+                flags: read_u32(buffer, endianness)?,
+                ehsize: read_u16(buffer, endianness)?,
+                phentsize: read_u16(buffer, endianness)?,
+                phnum: read_u16(buffer, endianness)?,
+                shentsize: read_u16(buffer, endianness)?,
+                shnum: read_u16(buffer, endianness)?,
+                shstrndx: read_u16(buffer, endianness)?,
+            })),
+        }
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.to_writer(writer, self.endianness())
+    }
+
+    pub fn class(&self) -> ElfClass {
+        match self {
+            ElfHeader::Elf32(_) => ElfClass::Elf32,
+            ElfHeader::Elf64(_) => ElfClass::Elf64,
+        }
+    }
+
+    pub fn endianness(&self) -> Endianness {
+        // Unwrap is safe: the ident byte was already validated in read_elf_header.
+        Endianness::from_ident_byte(self.ident()[EI_DATA]).unwrap()
+    }
+
+    pub fn ident(&self) -> [u8; 16] {
+        match self {
+            ElfHeader::Elf32(h) => h.ident,
+            ElfHeader::Elf64(h) => h.ident,
+        }
+    }
+
+    pub fn entry(&self) -> u64 {
+        match self {
+            ElfHeader::Elf32(h) => h.entry as u64,
+            ElfHeader::Elf64(h) => h.entry,
+        }
+    }
+
+    pub fn phoff(&self) -> u64 {
+        match self {
+            ElfHeader::Elf32(h) => h.phoff as u64,
+            ElfHeader::Elf64(h) => h.phoff,
+        }
+    }
+
+    pub fn shoff(&self) -> u64 {
+        match self {
+            ElfHeader::Elf32(h) => h.shoff as u64,
+            ElfHeader::Elf64(h) => h.shoff,
+        }
+    }
+
+    pub fn phentsize(&self) -> u16 {
+        match self {
+            ElfHeader::Elf32(h) => h.phentsize,
+            ElfHeader::Elf64(h) => h.phentsize,
+        }
+    }
+
+    pub fn phnum(&self) -> u16 {
+        match self {
+            ElfHeader::Elf32(h) => h.phnum,
+            ElfHeader::Elf64(h) => h.phnum,
+        }
+    }
+
+    pub fn shentsize(&self) -> u16 {
+        match self {
+            ElfHeader::Elf32(h) => h.shentsize,
+            ElfHeader::Elf64(h) => h.shentsize,
+        }
+    }
+
+    pub fn shnum(&self) -> u16 {
+        match self {
+            ElfHeader::Elf32(h) => h.shnum,
+            ElfHeader::Elf64(h) => h.shnum,
+        }
+    }
+
+    pub fn shstrndx(&self) -> u16 {
+        match self {
+            ElfHeader::Elf32(h) => h.shstrndx,
+            ElfHeader::Elf64(h) => h.shstrndx,
+        }
+    }
+}
+
+impl ToWriter for ElfHeader {
+    fn to_writer<W: Write>(&self, writer: &mut W, endianness: Endianness) -> Result<()> {
+        writer.write_all(&self.ident())?;
+        match self {
+            ElfHeader::Elf32(h) => {
+                write_u16(writer, h.typ, endianness)?;
+                write_u16(writer, h.machine, endianness)?;
+                write_u32(writer, h.version, endianness)?;
+                write_u32(writer, h.entry, endianness)?;
+                write_u32(writer, h.phoff, endianness)?;
+                write_u32(writer, h.shoff, endianness)?;
+                write_u32(writer, h.flags, endianness)?;
+                write_u16(writer, h.ehsize, endianness)?;
+                write_u16(writer, h.phentsize, endianness)?;
+                write_u16(writer, h.phnum, endianness)?;
+                write_u16(writer, h.shentsize, endianness)?;
+                write_u16(writer, h.shnum, endianness)?;
+                write_u16(writer, h.shstrndx, endianness)?;
+            }
+            ElfHeader::Elf64(h) => {
+                write_u16(writer, h.typ, endianness)?;
+                write_u16(writer, h.machine, endianness)?;
+                write_u32(writer, h.version, endianness)?;
+                write_u64(writer, h.entry, endianness)?;
+                write_u64(writer, h.phoff, endianness)?;
+                write_u64(writer, h.shoff, endianness)?;
+                write_u32(writer, h.flags, endianness)?;
+                write_u16(writer, h.ehsize, endianness)?;
+                write_u16(writer, h.phentsize, endianness)?;
+                write_u16(writer, h.phnum, endianness)?;
+                write_u16(writer, h.shentsize, endianness)?;
+                write_u16(writer, h.shnum, endianness)?;
+                write_u16(writer, h.shstrndx, endianness)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ident::{ELFCLASS32, ELFCLASS64, ELFDATA2LSB};
+    use std::io::Cursor;
+
+    fn build_ident(class: u8) -> [u8; 16] {
+        let mut ident = [0u8; 16];
+        ident[0..4].copy_from_slice(b"\x7fELF");
+        ident[EI_CLASS] = class;
+        ident[EI_DATA] = ELFDATA2LSB;
+        ident[6] = 1; // EI_VERSION
+        ident
+    }
+
+    #[test]
+    fn reads_elf32_header_and_dispatches_to_the_32_bit_variant() {
+        let endianness = Endianness::Little;
+        let mut buf = vec![];
+        buf.extend_from_slice(&build_ident(ELFCLASS32));
+        write_u16(&mut buf, 2, endianness).unwrap(); // e_type
+        write_u16(&mut buf, 0x03, endianness).unwrap(); // e_machine
+        write_u32(&mut buf, 1, endianness).unwrap(); // e_version
+        write_u32(&mut buf, 0x8048000, endianness).unwrap(); // e_entry
+        write_u32(&mut buf, 52, endianness).unwrap(); // e_phoff
+        write_u32(&mut buf, 0, endianness).unwrap(); // e_shoff
+        write_u32(&mut buf, 0, endianness).unwrap(); // e_flags
+        write_u16(&mut buf, 52, endianness).unwrap(); // e_ehsize
+        write_u16(&mut buf, 32, endianness).unwrap(); // e_phentsize
+        write_u16(&mut buf, 0, endianness).unwrap(); // e_phnum
+        write_u16(&mut buf, 40, endianness).unwrap(); // e_shentsize
+        write_u16(&mut buf, 0, endianness).unwrap(); // e_shnum
+        write_u16(&mut buf, 0, endianness).unwrap(); // e_shstrndx
+
+        let mut reader = BufReader::new(Cursor::new(buf));
+        let header = ElfHeader::read_elf_header(&mut reader).unwrap();
+
+        assert_eq!(header.class(), ElfClass::Elf32);
+        assert_eq!(header.entry(), 0x8048000);
+        assert_eq!(header.phentsize(), 32);
+        assert!(matches!(header, ElfHeader::Elf32(_)));
+    }
+
+    #[test]
+    fn reads_elf64_header_and_dispatches_to_the_64_bit_variant() {
+        let endianness = Endianness::Little;
+        let mut buf = vec![];
+        buf.extend_from_slice(&build_ident(ELFCLASS64));
+        write_u16(&mut buf, 2, endianness).unwrap(); // e_type
+        write_u16(&mut buf, 0x3e, endianness).unwrap(); // e_machine
+        write_u32(&mut buf, 1, endianness).unwrap(); // e_version
+        write_u64(&mut buf, 0x401000, endianness).unwrap(); // e_entry
+        write_u64(&mut buf, 64, endianness).unwrap(); // e_phoff
+        write_u64(&mut buf, 0, endianness).unwrap(); // e_shoff
+        write_u32(&mut buf, 0, endianness).unwrap(); // e_flags
+        write_u16(&mut buf, 64, endianness).unwrap(); // e_ehsize
+        write_u16(&mut buf, 56, endianness).unwrap(); // e_phentsize
+        write_u16(&mut buf, 0, endianness).unwrap(); // e_phnum
+        write_u16(&mut buf, 64, endianness).unwrap(); // e_shentsize
+        write_u16(&mut buf, 0, endianness).unwrap(); // e_shnum
+        write_u16(&mut buf, 0, endianness).unwrap(); // e_shstrndx
+
+        let mut reader = BufReader::new(Cursor::new(buf));
+        let header = ElfHeader::read_elf_header(&mut reader).unwrap();
+
+        assert_eq!(header.class(), ElfClass::Elf64);
+        assert_eq!(header.entry(), 0x401000);
+        assert_eq!(header.phentsize(), 56);
+        assert!(matches!(header, ElfHeader::Elf64(_)));
+    }
+}
@@ -0,0 +1,42 @@
+use crate::error::{Error, Result};
+
+pub const EI_CLASS: usize = 4;
+pub const EI_DATA: usize = 5;
+
+pub const ELFCLASS32: u8 = 1;
+pub const ELFCLASS64: u8 = 2;
+
+pub const ELFDATA2LSB: u8 = 1;
+pub const ELFDATA2MSB: u8 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElfClass {
+    Elf32,
+    Elf64,
+}
+
+impl ElfClass {
+    pub fn from_ident_byte(byte: u8) -> Result<Self> {
+        match byte {
+            ELFCLASS32 => Ok(ElfClass::Elf32),
+            ELFCLASS64 => Ok(ElfClass::Elf64),
+            other => Err(Error::InvalidClass(other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
+impl Endianness {
+    pub fn from_ident_byte(byte: u8) -> Result<Self> {
+        match byte {
+            ELFDATA2LSB => Ok(Endianness::Little),
+            ELFDATA2MSB => Ok(Endianness::Big),
+            other => Err(Error::InvalidData(other)),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+pub mod elf;
+pub mod error;
+pub mod header;
+pub mod ident;
+pub mod note;
+pub mod program_header;
+pub mod reader;
+pub mod reloc;
+pub mod section_header;
+pub mod symbol;
+pub mod writer;
+
+pub use elf::Elf;
+pub use error::{Error, Result};
+pub use header::ElfHeader;
+pub use note::Note;
+pub use program_header::ProgramHeader;
+pub use reloc::{ElfRel, ElfRela, Relocation, ResolvedRelocation};
+pub use section_header::SectionHeader;
+pub use symbol::ElfSymbol;
@@ -0,0 +1,101 @@
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+use crate::error::{Error, Result};
+use crate::ident::Endianness;
+use crate::reader::read_u32;
+
+pub const SHT_NOTE: u32 = 7;
+
+#[derive(Debug, Clone)]
+pub struct Note {
+    pub name: Vec<u8>,
+    pub typ: u32,
+    pub descriptor: Vec<u8>,
+}
+
+fn align4(n: u64) -> u64 {
+    (n + 3) & !3
+}
+
+pub fn parse_notes(data: &[u8], endianness: Endianness) -> Result<Vec<Note>> {
+    let mut cursor = Cursor::new(data);
+    let mut notes = vec![];
+
+    while (cursor.position() as usize) < data.len() {
+        let namesz = read_u32(&mut cursor, endianness)? as u64;
+        let descsz = read_u32(&mut cursor, endianness)? as u64;
+        let typ = read_u32(&mut cursor, endianness)?;
+
+        let remaining = (data.len() - cursor.position() as usize) as u64;
+        let needed = align4(namesz) + align4(descsz);
+        if needed > remaining {
+            return Err(Error::Malformed("note record size exceeds section bounds"));
+        }
+
+        let mut name = vec![0u8; namesz as usize];
+        cursor.read_exact(&mut name)?;
+        cursor.seek(SeekFrom::Current((align4(namesz) - namesz) as i64))?;
+
+        let mut descriptor = vec![0u8; descsz as usize];
+        cursor.read_exact(&mut descriptor)?;
+        cursor.seek(SeekFrom::Current((align4(descsz) - descsz) as i64))?;
+
+        notes.push(Note {
+            name,
+            typ,
+            descriptor,
+        });
+    }
+
+    Ok(notes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::write_u32;
+
+    #[test]
+    fn parses_a_well_formed_note() {
+        let endianness = Endianness::Little;
+        let mut data = vec![];
+        write_u32(&mut data, 4, endianness).unwrap(); // namesz
+        write_u32(&mut data, 4, endianness).unwrap(); // descsz
+        write_u32(&mut data, 3, endianness).unwrap(); // type (NT_GNU_BUILD_ID)
+        data.extend_from_slice(b"GNU\0");
+        data.extend_from_slice(&[0xaa, 0xbb, 0xcc, 0xdd]);
+
+        let notes = parse_notes(&data, endianness).unwrap();
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].name, b"GNU\0");
+        assert_eq!(notes[0].descriptor, [0xaa, 0xbb, 0xcc, 0xdd]);
+    }
+
+    #[test]
+    fn rejects_namesz_near_u32_max_without_overflow_or_huge_allocation() {
+        let endianness = Endianness::Little;
+        let mut data = vec![];
+        write_u32(&mut data, u32::MAX, endianness).unwrap(); // namesz
+        write_u32(&mut data, 0, endianness).unwrap(); // descsz
+        write_u32(&mut data, 0, endianness).unwrap(); // type
+
+        assert!(matches!(
+            parse_notes(&data, endianness),
+            Err(Error::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_a_note_whose_claimed_size_exceeds_the_data() {
+        let endianness = Endianness::Little;
+        let mut data = vec![];
+        write_u32(&mut data, 100, endianness).unwrap(); // namesz
+        write_u32(&mut data, 0, endianness).unwrap(); // descsz
+        write_u32(&mut data, 0, endianness).unwrap(); // type
+
+        assert!(matches!(
+            parse_notes(&data, endianness),
+            Err(Error::Malformed(_))
+        ));
+    }
+}
@@ -0,0 +1,196 @@
+use std::io::{BufReader, Read, Seek, SeekFrom, Write};
+
+use crate::error::{Error, Result};
+use crate::header::ElfHeader;
+use crate::ident::{ElfClass, Endianness};
+use crate::reader::{read_u32, read_u64, FromReader};
+use crate::writer::{write_u32, write_u64, ToWriter};
+
+const ELF32_PHENTSIZE: usize = 32;
+const ELF64_PHENTSIZE: usize = 56;
+
+pub const PT_LOAD: u32 = 1;
+pub const PT_NOTE: u32 = 4;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ProgramHeader32 {
+    pub typ: u32,
+    pub offset: u32,
+    pub vaddr: u32,
+    pub paddr: u32,
+    pub filesz: u32,
+    pub memsz: u32,
+    pub flags: u32,
+    pub align: u32,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ProgramHeader64 {
+    pub typ: u32,
+    pub flags: u32,
+    pub offset: u64,
+    pub vaddr: u64,
+    pub paddr: u64,
+    pub filesz: u64,
+    pub memsz: u64,
+    pub align: u64,
+}
+
+impl FromReader for ProgramHeader32 {
+    fn from_reader<R: Read>(reader: &mut R, endianness: Endianness) -> Result<Self> {
+        Ok(ProgramHeader32 {
+            typ: read_u32(reader, endianness)?,
+            offset: read_u32(reader, endianness)?,
+            vaddr: read_u32(reader, endianness)?,
+            paddr: read_u32(reader, endianness)?,
+            filesz: read_u32(reader, endianness)?,
+            memsz: read_u32(reader, endianness)?,
+            flags: read_u32(reader, endianness)?,
+            align: read_u32(reader, endianness)?,
+        })
+    }
+}
+
+impl FromReader for ProgramHeader64 {
+    fn from_reader<R: Read>(reader: &mut R, endianness: Endianness) -> Result<Self> {
+        Ok(ProgramHeader64 {
+            typ: read_u32(reader, endianness)?,
+            flags: read_u32(reader, endianness)?,
+            offset: read_u64(reader, endianness)?,
+            vaddr: read_u64(reader, endianness)?,
+            paddr: read_u64(reader, endianness)?,
+            filesz: read_u64(reader, endianness)?,
+            memsz: read_u64(reader, endianness)?,
+            align: read_u64(reader, endianness)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ProgramHeader {
+    Elf32(ProgramHeader32),
+    Elf64(ProgramHeader64),
+}
+
+impl ProgramHeader {
+    pub fn read_program_headers<R: Read + Seek>(
+        buffer: &mut BufReader<R>,
+        elf_header: &ElfHeader,
+    ) -> Result<Vec<ProgramHeader>> {
+        let endianness = elf_header.endianness();
+        let expected = match elf_header.class() {
+            ElfClass::Elf32 => ELF32_PHENTSIZE,
+            ElfClass::Elf64 => ELF64_PHENTSIZE,
+        };
+        if elf_header.phentsize() as usize != expected {
+            return Err(Error::InvalidEntrySize {
+                expected,
+                found: elf_header.phentsize(),
+            });
+        }
+
+        let mut headers = vec![];
+        buffer.seek(SeekFrom::Start(elf_header.phoff()))?;
+        for _ in 0..elf_header.phnum() {
+            let header = match elf_header.class() {
+                ElfClass::Elf32 => {
+                    ProgramHeader::Elf32(ProgramHeader32::from_reader(buffer, endianness)?)
+                }
+                ElfClass::Elf64 => {
+                    ProgramHeader::Elf64(ProgramHeader64::from_reader(buffer, endianness)?)
+                }
+            };
+            headers.push(header);
+        }
+
+        Ok(headers)
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W, endianness: Endianness) -> Result<()> {
+        self.to_writer(writer, endianness)
+    }
+
+    pub fn typ(&self) -> u32 {
+        match self {
+            ProgramHeader::Elf32(h) => h.typ,
+            ProgramHeader::Elf64(h) => h.typ,
+        }
+    }
+
+    pub fn offset(&self) -> u64 {
+        match self {
+            ProgramHeader::Elf32(h) => h.offset as u64,
+            ProgramHeader::Elf64(h) => h.offset,
+        }
+    }
+
+    pub fn vaddr(&self) -> u64 {
+        match self {
+            ProgramHeader::Elf32(h) => h.vaddr as u64,
+            ProgramHeader::Elf64(h) => h.vaddr,
+        }
+    }
+
+    pub fn paddr(&self) -> u64 {
+        match self {
+            ProgramHeader::Elf32(h) => h.paddr as u64,
+            ProgramHeader::Elf64(h) => h.paddr,
+        }
+    }
+
+    pub fn filesz(&self) -> u64 {
+        match self {
+            ProgramHeader::Elf32(h) => h.filesz as u64,
+            ProgramHeader::Elf64(h) => h.filesz,
+        }
+    }
+
+    pub fn memsz(&self) -> u64 {
+        match self {
+            ProgramHeader::Elf32(h) => h.memsz as u64,
+            ProgramHeader::Elf64(h) => h.memsz,
+        }
+    }
+
+    pub fn flags(&self) -> u32 {
+        match self {
+            ProgramHeader::Elf32(h) => h.flags,
+            ProgramHeader::Elf64(h) => h.flags,
+        }
+    }
+
+    pub fn align(&self) -> u64 {
+        match self {
+            ProgramHeader::Elf32(h) => h.align as u64,
+            ProgramHeader::Elf64(h) => h.align,
+        }
+    }
+}
+
+impl ToWriter for ProgramHeader {
+    fn to_writer<W: Write>(&self, writer: &mut W, endianness: Endianness) -> Result<()> {
+        match self {
+            ProgramHeader::Elf32(h) => {
+                write_u32(writer, h.typ, endianness)?;
+                write_u32(writer, h.offset, endianness)?;
+                write_u32(writer, h.vaddr, endianness)?;
+                write_u32(writer, h.paddr, endianness)?;
+                write_u32(writer, h.filesz, endianness)?;
+                write_u32(writer, h.memsz, endianness)?;
+                write_u32(writer, h.flags, endianness)?;
+                write_u32(writer, h.align, endianness)?;
+            }
+            ProgramHeader::Elf64(h) => {
+                write_u32(writer, h.typ, endianness)?;
+                write_u32(writer, h.flags, endianness)?;
+                write_u64(writer, h.offset, endianness)?;
+                write_u64(writer, h.vaddr, endianness)?;
+                write_u64(writer, h.paddr, endianness)?;
+                write_u64(writer, h.filesz, endianness)?;
+                write_u64(writer, h.memsz, endianness)?;
+                write_u64(writer, h.align, endianness)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use std::io::Read;
+
+use crate::error::Result;
+use crate::ident::Endianness;
+
+pub trait FromReader: Sized {
+    fn from_reader<R: Read>(reader: &mut R, endianness: Endianness) -> Result<Self>;
+}
+
+pub fn read_u8<R: Read>(reader: &mut R) -> Result<u8> {
+    let mut buf = [0u8; 1];
+    reader.read_exact(&mut buf)?;
+    Ok(buf[0])
+}
+
+pub fn read_u16<R: Read>(reader: &mut R, endianness: Endianness) -> Result<u16> {
+    let mut buf = [0u8; 2];
+    reader.read_exact(&mut buf)?;
+    Ok(match endianness {
+        Endianness::Little => u16::from_le_bytes(buf),
+        Endianness::Big => u16::from_be_bytes(buf),
+    })
+}
+
+pub fn read_u32<R: Read>(reader: &mut R, endianness: Endianness) -> Result<u32> {
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(match endianness {
+        Endianness::Little => u32::from_le_bytes(buf),
+        Endianness::Big => u32::from_be_bytes(buf),
+    })
+}
+
+pub fn read_u64<R: Read>(reader: &mut R, endianness: Endianness) -> Result<u64> {
+    let mut buf = [0u8; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(match endianness {
+        Endianness::Little => u64::from_le_bytes(buf),
+        Endianness::Big => u64::from_be_bytes(buf),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn reads_u16_little_and_big_endian() {
+        let mut le = Cursor::new([0x34, 0x12]);
+        assert_eq!(read_u16(&mut le, Endianness::Little).unwrap(), 0x1234);
+
+        let mut be = Cursor::new([0x12, 0x34]);
+        assert_eq!(read_u16(&mut be, Endianness::Big).unwrap(), 0x1234);
+    }
+
+    #[test]
+    fn reads_u32_little_and_big_endian() {
+        let mut le = Cursor::new([0x78, 0x56, 0x34, 0x12]);
+        assert_eq!(read_u32(&mut le, Endianness::Little).unwrap(), 0x1234_5678);
+
+        let mut be = Cursor::new([0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(read_u32(&mut be, Endianness::Big).unwrap(), 0x1234_5678);
+    }
+
+    #[test]
+    fn reads_u64_little_and_big_endian() {
+        let mut le = Cursor::new([0xf0, 0xde, 0xbc, 0x9a, 0x78, 0x56, 0x34, 0x12]);
+        assert_eq!(
+            read_u64(&mut le, Endianness::Little).unwrap(),
+            0x1234_5678_9abc_def0
+        );
+
+        let mut be = Cursor::new([0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0]);
+        assert_eq!(
+            read_u64(&mut be, Endianness::Big).unwrap(),
+            0x1234_5678_9abc_def0
+        );
+    }
+
+    #[test]
+    fn read_u64_errors_on_truncated_input() {
+        let mut short = Cursor::new([0x01, 0x02, 0x03]);
+        assert!(read_u64(&mut short, Endianness::Little).is_err());
+    }
+}
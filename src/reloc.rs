@@ -0,0 +1,210 @@
+use std::io::Read;
+
+use crate::error::Result;
+use crate::ident::{ElfClass, Endianness};
+use crate::reader::{read_u32, read_u64, FromReader};
+use crate::symbol::ElfSymbol;
+
+/// A decoded relocation paired with its resolved symbol, if any.
+pub type ResolvedRelocation = (Relocation, Option<(String, ElfSymbol)>);
+
+pub const SHT_RELA: u32 = 4;
+pub const SHT_REL: u32 = 9;
+
+const ELF32_RELENTSIZE: usize = 8;
+const ELF64_RELENTSIZE: usize = 16;
+const ELF32_RELAENTSIZE: usize = 12;
+const ELF64_RELAENTSIZE: usize = 24;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ElfRel32 {
+    pub offset: u32,
+    pub info: u32,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ElfRel64 {
+    pub offset: u64,
+    pub info: u64,
+}
+
+impl FromReader for ElfRel32 {
+    fn from_reader<R: Read>(reader: &mut R, endianness: Endianness) -> Result<Self> {
+        Ok(ElfRel32 {
+            offset: read_u32(reader, endianness)?,
+            info: read_u32(reader, endianness)?,
+        })
+    }
+}
+
+impl FromReader for ElfRel64 {
+    fn from_reader<R: Read>(reader: &mut R, endianness: Endianness) -> Result<Self> {
+        Ok(ElfRel64 {
+            offset: read_u64(reader, endianness)?,
+            info: read_u64(reader, endianness)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ElfRel {
+    Elf32(ElfRel32),
+    Elf64(ElfRel64),
+}
+
+impl ElfRel {
+    pub fn entsize(class: ElfClass) -> usize {
+        match class {
+            ElfClass::Elf32 => ELF32_RELENTSIZE,
+            ElfClass::Elf64 => ELF64_RELENTSIZE,
+        }
+    }
+
+    pub fn read<R: Read>(reader: &mut R, class: ElfClass, endianness: Endianness) -> Result<Self> {
+        match class {
+            ElfClass::Elf32 => Ok(ElfRel::Elf32(ElfRel32::from_reader(reader, endianness)?)),
+            ElfClass::Elf64 => Ok(ElfRel::Elf64(ElfRel64::from_reader(reader, endianness)?)),
+        }
+    }
+
+    pub fn offset(&self) -> u64 {
+        match self {
+            ElfRel::Elf32(r) => r.offset as u64,
+            ElfRel::Elf64(r) => r.offset,
+        }
+    }
+
+    pub fn sym(&self) -> u64 {
+        match self {
+            ElfRel::Elf32(r) => (r.info >> 8) as u64,
+            ElfRel::Elf64(r) => r.info >> 32,
+        }
+    }
+
+    pub fn rtype(&self) -> u32 {
+        match self {
+            ElfRel::Elf32(r) => r.info & 0xff,
+            ElfRel::Elf64(r) => (r.info & 0xffff_ffff) as u32,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ElfRela32 {
+    pub offset: u32,
+    pub info: u32,
+    pub addend: i32,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ElfRela64 {
+    pub offset: u64,
+    pub info: u64,
+    pub addend: i64,
+}
+
+impl FromReader for ElfRela32 {
+    fn from_reader<R: Read>(reader: &mut R, endianness: Endianness) -> Result<Self> {
+        Ok(ElfRela32 {
+            offset: read_u32(reader, endianness)?,
+            info: read_u32(reader, endianness)?,
+            addend: read_u32(reader, endianness)? as i32,
+        })
+    }
+}
+
+impl FromReader for ElfRela64 {
+    fn from_reader<R: Read>(reader: &mut R, endianness: Endianness) -> Result<Self> {
+        Ok(ElfRela64 {
+            offset: read_u64(reader, endianness)?,
+            info: read_u64(reader, endianness)?,
+            addend: read_u64(reader, endianness)? as i64,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ElfRela {
+    Elf32(ElfRela32),
+    Elf64(ElfRela64),
+}
+
+impl ElfRela {
+    pub fn entsize(class: ElfClass) -> usize {
+        match class {
+            ElfClass::Elf32 => ELF32_RELAENTSIZE,
+            ElfClass::Elf64 => ELF64_RELAENTSIZE,
+        }
+    }
+
+    pub fn read<R: Read>(reader: &mut R, class: ElfClass, endianness: Endianness) -> Result<Self> {
+        match class {
+            ElfClass::Elf32 => Ok(ElfRela::Elf32(ElfRela32::from_reader(reader, endianness)?)),
+            ElfClass::Elf64 => Ok(ElfRela::Elf64(ElfRela64::from_reader(reader, endianness)?)),
+        }
+    }
+
+    pub fn offset(&self) -> u64 {
+        match self {
+            ElfRela::Elf32(r) => r.offset as u64,
+            ElfRela::Elf64(r) => r.offset,
+        }
+    }
+
+    pub fn sym(&self) -> u64 {
+        match self {
+            ElfRela::Elf32(r) => (r.info >> 8) as u64,
+            ElfRela::Elf64(r) => r.info >> 32,
+        }
+    }
+
+    pub fn rtype(&self) -> u32 {
+        match self {
+            ElfRela::Elf32(r) => r.info & 0xff,
+            ElfRela::Elf64(r) => (r.info & 0xffff_ffff) as u32,
+        }
+    }
+
+    pub fn addend(&self) -> i64 {
+        match self {
+            ElfRela::Elf32(r) => r.addend as i64,
+            ElfRela::Elf64(r) => r.addend,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Relocation {
+    Rel(ElfRel),
+    Rela(ElfRela),
+}
+
+impl Relocation {
+    pub fn offset(&self) -> u64 {
+        match self {
+            Relocation::Rel(r) => r.offset(),
+            Relocation::Rela(r) => r.offset(),
+        }
+    }
+
+    pub fn sym(&self) -> u64 {
+        match self {
+            Relocation::Rel(r) => r.sym(),
+            Relocation::Rela(r) => r.sym(),
+        }
+    }
+
+    pub fn rtype(&self) -> u32 {
+        match self {
+            Relocation::Rel(r) => r.rtype(),
+            Relocation::Rela(r) => r.rtype(),
+        }
+    }
+
+    pub fn addend(&self) -> Option<i64> {
+        match self {
+            Relocation::Rel(_) => None,
+            Relocation::Rela(r) => Some(r.addend()),
+        }
+    }
+}
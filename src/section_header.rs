@@ -0,0 +1,219 @@
+use std::io::{BufReader, Read, Seek, SeekFrom, Write};
+
+use crate::error::{Error, Result};
+use crate::header::ElfHeader;
+use crate::ident::{ElfClass, Endianness};
+use crate::reader::{read_u32, read_u64, FromReader};
+use crate::writer::{write_u32, write_u64, ToWriter};
+
+const ELF32_SHENTSIZE: usize = 40;
+const ELF64_SHENTSIZE: usize = 64;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SectionHeader32 {
+    pub name: u32,
+    pub typ: u32,
+    pub flags: u32,
+    pub addr: u32,
+    pub offset: u32,
+    pub size: u32,
+    pub link: u32,
+    pub info: u32,
+    pub addralign: u32,
+    pub entsize: u32,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SectionHeader64 {
+    pub name: u32,
+    pub typ: u32,
+    pub flags: u64,
+    pub addr: u64,
+    pub offset: u64,
+    pub size: u64,
+    pub link: u32,
+    pub info: u32,
+    pub addralign: u64,
+    pub entsize: u64,
+}
+
+impl FromReader for SectionHeader32 {
+    fn from_reader<R: Read>(reader: &mut R, endianness: Endianness) -> Result<Self> {
+        Ok(SectionHeader32 {
+            name: read_u32(reader, endianness)?,
+            typ: read_u32(reader, endianness)?,
+            flags: read_u32(reader, endianness)?,
+            addr: read_u32(reader, endianness)?,
+            offset: read_u32(reader, endianness)?,
+            size: read_u32(reader, endianness)?,
+            link: read_u32(reader, endianness)?,
+            info: read_u32(reader, endianness)?,
+            addralign: read_u32(reader, endianness)?,
+            entsize: read_u32(reader, endianness)?,
+        })
+    }
+}
+
+impl FromReader for SectionHeader64 {
+    fn from_reader<R: Read>(reader: &mut R, endianness: Endianness) -> Result<Self> {
+        Ok(SectionHeader64 {
+            name: read_u32(reader, endianness)?,
+            typ: read_u32(reader, endianness)?,
+            flags: read_u64(reader, endianness)?,
+            addr: read_u64(reader, endianness)?,
+            offset: read_u64(reader, endianness)?,
+            size: read_u64(reader, endianness)?,
+            link: read_u32(reader, endianness)?,
+            info: read_u32(reader, endianness)?,
+            addralign: read_u64(reader, endianness)?,
+            entsize: read_u64(reader, endianness)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SectionHeader {
+    Elf32(SectionHeader32),
+    Elf64(SectionHeader64),
+}
+
+impl SectionHeader {
+    pub fn read_section_headers<R: Read + Seek>(
+        buffer: &mut BufReader<R>,
+        elf_header: &ElfHeader,
+    ) -> Result<Vec<SectionHeader>> {
+        let endianness = elf_header.endianness();
+        let expected = match elf_header.class() {
+            ElfClass::Elf32 => ELF32_SHENTSIZE,
+            ElfClass::Elf64 => ELF64_SHENTSIZE,
+        };
+        if elf_header.shentsize() as usize != expected {
+            return Err(Error::InvalidEntrySize {
+                expected,
+                found: elf_header.shentsize(),
+            });
+        }
+
+        let mut headers = vec![];
+        buffer.seek(SeekFrom::Start(elf_header.shoff()))?;
+        for _ in 0..elf_header.shnum() {
+            let header = match elf_header.class() {
+                ElfClass::Elf32 => {
+                    SectionHeader::Elf32(SectionHeader32::from_reader(buffer, endianness)?)
+                }
+                ElfClass::Elf64 => {
+                    SectionHeader::Elf64(SectionHeader64::from_reader(buffer, endianness)?)
+                }
+            };
+            headers.push(header);
+        }
+
+        Ok(headers)
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W, endianness: Endianness) -> Result<()> {
+        self.to_writer(writer, endianness)
+    }
+
+    pub fn name(&self) -> u32 {
+        match self {
+            SectionHeader::Elf32(h) => h.name,
+            SectionHeader::Elf64(h) => h.name,
+        }
+    }
+
+    pub fn typ(&self) -> u32 {
+        match self {
+            SectionHeader::Elf32(h) => h.typ,
+            SectionHeader::Elf64(h) => h.typ,
+        }
+    }
+
+    pub fn flags(&self) -> u64 {
+        match self {
+            SectionHeader::Elf32(h) => h.flags as u64,
+            SectionHeader::Elf64(h) => h.flags,
+        }
+    }
+
+    pub fn addr(&self) -> u64 {
+        match self {
+            SectionHeader::Elf32(h) => h.addr as u64,
+            SectionHeader::Elf64(h) => h.addr,
+        }
+    }
+
+    pub fn offset(&self) -> u64 {
+        match self {
+            SectionHeader::Elf32(h) => h.offset as u64,
+            SectionHeader::Elf64(h) => h.offset,
+        }
+    }
+
+    pub fn size(&self) -> u64 {
+        match self {
+            SectionHeader::Elf32(h) => h.size as u64,
+            SectionHeader::Elf64(h) => h.size,
+        }
+    }
+
+    pub fn link(&self) -> u32 {
+        match self {
+            SectionHeader::Elf32(h) => h.link,
+            SectionHeader::Elf64(h) => h.link,
+        }
+    }
+
+    pub fn info(&self) -> u32 {
+        match self {
+            SectionHeader::Elf32(h) => h.info,
+            SectionHeader::Elf64(h) => h.info,
+        }
+    }
+
+    pub fn addralign(&self) -> u64 {
+        match self {
+            SectionHeader::Elf32(h) => h.addralign as u64,
+            SectionHeader::Elf64(h) => h.addralign,
+        }
+    }
+
+    pub fn entsize(&self) -> u64 {
+        match self {
+            SectionHeader::Elf32(h) => h.entsize as u64,
+            SectionHeader::Elf64(h) => h.entsize,
+        }
+    }
+}
+
+impl ToWriter for SectionHeader {
+    fn to_writer<W: Write>(&self, writer: &mut W, endianness: Endianness) -> Result<()> {
+        match self {
+            SectionHeader::Elf32(h) => {
+                write_u32(writer, h.name, endianness)?;
+                write_u32(writer, h.typ, endianness)?;
+                write_u32(writer, h.flags, endianness)?;
+                write_u32(writer, h.addr, endianness)?;
+                write_u32(writer, h.offset, endianness)?;
+                write_u32(writer, h.size, endianness)?;
+                write_u32(writer, h.link, endianness)?;
+                write_u32(writer, h.info, endianness)?;
+                write_u32(writer, h.addralign, endianness)?;
+                write_u32(writer, h.entsize, endianness)?;
+            }
+            SectionHeader::Elf64(h) => {
+                write_u32(writer, h.name, endianness)?;
+                write_u32(writer, h.typ, endianness)?;
+                write_u64(writer, h.flags, endianness)?;
+                write_u64(writer, h.addr, endianness)?;
+                write_u64(writer, h.offset, endianness)?;
+                write_u64(writer, h.size, endianness)?;
+                write_u32(writer, h.link, endianness)?;
+                write_u32(writer, h.info, endianness)?;
+                write_u64(writer, h.addralign, endianness)?;
+                write_u64(writer, h.entsize, endianness)?;
+            }
+        }
+        Ok(())
+    }
+}
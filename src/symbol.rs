@@ -0,0 +1,129 @@
+use std::io::Read;
+
+use crate::error::Result;
+use crate::ident::{ElfClass, Endianness};
+use crate::reader::{read_u16, read_u32, read_u64, read_u8, FromReader};
+
+pub const SHT_SYMTAB: u32 = 2;
+pub const SHT_DYNSYM: u32 = 11;
+
+const ELF32_SYMENTSIZE: usize = 16;
+const ELF64_SYMENTSIZE: usize = 24;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ElfSymbol32 {
+    pub name: u32,
+    pub value: u32,
+    pub size: u32,
+    pub info: u8,
+    pub other: u8,
+    pub shndx: u16,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ElfSymbol64 {
+    pub name: u32,
+    pub info: u8,
+    pub other: u8,
+    pub shndx: u16,
+    pub value: u64,
+    pub size: u64,
+}
+
+impl FromReader for ElfSymbol32 {
+    fn from_reader<R: Read>(reader: &mut R, endianness: Endianness) -> Result<Self> {
+        Ok(ElfSymbol32 {
+            name: read_u32(reader, endianness)?,
+            value: read_u32(reader, endianness)?,
+            size: read_u32(reader, endianness)?,
+            info: read_u8(reader)?,
+            other: read_u8(reader)?,
+            shndx: read_u16(reader, endianness)?,
+        })
+    }
+}
+
+impl FromReader for ElfSymbol64 {
+    fn from_reader<R: Read>(reader: &mut R, endianness: Endianness) -> Result<Self> {
+        Ok(ElfSymbol64 {
+            name: read_u32(reader, endianness)?,
+            info: read_u8(reader)?,
+            other: read_u8(reader)?,
+            shndx: read_u16(reader, endianness)?,
+            value: read_u64(reader, endianness)?,
+            size: read_u64(reader, endianness)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ElfSymbol {
+    Elf32(ElfSymbol32),
+    Elf64(ElfSymbol64),
+}
+
+impl ElfSymbol {
+    pub fn entsize(class: ElfClass) -> usize {
+        match class {
+            ElfClass::Elf32 => ELF32_SYMENTSIZE,
+            ElfClass::Elf64 => ELF64_SYMENTSIZE,
+        }
+    }
+
+    pub fn read<R: Read>(reader: &mut R, class: ElfClass, endianness: Endianness) -> Result<Self> {
+        match class {
+            ElfClass::Elf32 => Ok(ElfSymbol::Elf32(ElfSymbol32::from_reader(reader, endianness)?)),
+            ElfClass::Elf64 => Ok(ElfSymbol::Elf64(ElfSymbol64::from_reader(reader, endianness)?)),
+        }
+    }
+
+    pub fn name(&self) -> u32 {
+        match self {
+            ElfSymbol::Elf32(s) => s.name,
+            ElfSymbol::Elf64(s) => s.name,
+        }
+    }
+
+    pub fn value(&self) -> u64 {
+        match self {
+            ElfSymbol::Elf32(s) => s.value as u64,
+            ElfSymbol::Elf64(s) => s.value,
+        }
+    }
+
+    pub fn size(&self) -> u64 {
+        match self {
+            ElfSymbol::Elf32(s) => s.size as u64,
+            ElfSymbol::Elf64(s) => s.size,
+        }
+    }
+
+    pub fn info(&self) -> u8 {
+        match self {
+            ElfSymbol::Elf32(s) => s.info,
+            ElfSymbol::Elf64(s) => s.info,
+        }
+    }
+
+    pub fn other(&self) -> u8 {
+        match self {
+            ElfSymbol::Elf32(s) => s.other,
+            ElfSymbol::Elf64(s) => s.other,
+        }
+    }
+
+    pub fn shndx(&self) -> u16 {
+        match self {
+            ElfSymbol::Elf32(s) => s.shndx,
+            ElfSymbol::Elf64(s) => s.shndx,
+        }
+    }
+
+    pub fn binding(&self) -> u8 {
+        self.info() >> 4
+    }
+
+    pub fn sym_type(&self) -> u8 {
+        self.info() & 0xf
+    }
+}
@@ -0,0 +1,78 @@
+use std::io::Write;
+
+use crate::error::Result;
+use crate::ident::Endianness;
+
+pub trait ToWriter {
+    fn to_writer<W: Write>(&self, writer: &mut W, endianness: Endianness) -> Result<()>;
+}
+
+pub fn write_u8<W: Write>(writer: &mut W, value: u8) -> Result<()> {
+    writer.write_all(&[value])?;
+    Ok(())
+}
+
+pub fn write_u16<W: Write>(writer: &mut W, value: u16, endianness: Endianness) -> Result<()> {
+    let bytes = match endianness {
+        Endianness::Little => value.to_le_bytes(),
+        Endianness::Big => value.to_be_bytes(),
+    };
+    writer.write_all(&bytes)?;
+    Ok(())
+}
+
+pub fn write_u32<W: Write>(writer: &mut W, value: u32, endianness: Endianness) -> Result<()> {
+    let bytes = match endianness {
+        Endianness::Little => value.to_le_bytes(),
+        Endianness::Big => value.to_be_bytes(),
+    };
+    writer.write_all(&bytes)?;
+    Ok(())
+}
+
+pub fn write_u64<W: Write>(writer: &mut W, value: u64, endianness: Endianness) -> Result<()> {
+    let bytes = match endianness {
+        Endianness::Little => value.to_le_bytes(),
+        Endianness::Big => value.to_be_bytes(),
+    };
+    writer.write_all(&bytes)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_u16_little_and_big_endian() {
+        let mut le = vec![];
+        write_u16(&mut le, 0x1234, Endianness::Little).unwrap();
+        assert_eq!(le, [0x34, 0x12]);
+
+        let mut be = vec![];
+        write_u16(&mut be, 0x1234, Endianness::Big).unwrap();
+        assert_eq!(be, [0x12, 0x34]);
+    }
+
+    #[test]
+    fn writes_u32_little_and_big_endian() {
+        let mut le = vec![];
+        write_u32(&mut le, 0x1234_5678, Endianness::Little).unwrap();
+        assert_eq!(le, [0x78, 0x56, 0x34, 0x12]);
+
+        let mut be = vec![];
+        write_u32(&mut be, 0x1234_5678, Endianness::Big).unwrap();
+        assert_eq!(be, [0x12, 0x34, 0x56, 0x78]);
+    }
+
+    #[test]
+    fn writes_u64_little_and_big_endian() {
+        let mut le = vec![];
+        write_u64(&mut le, 0x1234_5678_9abc_def0, Endianness::Little).unwrap();
+        assert_eq!(le, [0xf0, 0xde, 0xbc, 0x9a, 0x78, 0x56, 0x34, 0x12]);
+
+        let mut be = vec![];
+        write_u64(&mut be, 0x1234_5678_9abc_def0, Endianness::Big).unwrap();
+        assert_eq!(be, [0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0]);
+    }
+}